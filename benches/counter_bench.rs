@@ -0,0 +1,91 @@
+use std::cell::Cell;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use thread_local::ThreadLocal;
+
+// Criterion samples each benchmark many times, so the per-iteration
+// workload is kept much smaller than the NTHREADS/NITERATIONS used by the
+// standalone examples and rust-working-example/bench.rs.
+static NTHREADS: u32 = 4;
+static NITERATIONS: u32 = 50_000;
+
+struct Counter {
+    val: u32,
+}
+
+fn mutex_counter() -> u32 {
+    let counter = Arc::new(Mutex::new(Counter { val: 0 }));
+
+    let mut children = vec![];
+    for _ in 0..NTHREADS {
+        let counter = Arc::clone(&counter);
+        children.push(thread::spawn(move || {
+            for _ in 0..NITERATIONS {
+                counter.lock().unwrap().val += 1;
+            }
+        }));
+    }
+    for child in children {
+        let _ = child.join();
+    }
+
+    let val = counter.lock().unwrap().val;
+    val
+}
+
+fn atomic_counter(ordering: Ordering) -> usize {
+    let counter = Arc::new(AtomicUsize::new(0));
+
+    let mut children = vec![];
+    for _ in 0..NTHREADS {
+        let counter = Arc::clone(&counter);
+        children.push(thread::spawn(move || {
+            for _ in 0..NITERATIONS {
+                counter.fetch_add(1, ordering);
+            }
+        }));
+    }
+    for child in children {
+        let _ = child.join();
+    }
+
+    counter.load(Ordering::SeqCst)
+}
+
+fn thread_local_counter() -> u64 {
+    let counters: Arc<ThreadLocal<Cell<u64>>> = Arc::new(ThreadLocal::new());
+
+    let mut children = vec![];
+    for _ in 0..NTHREADS {
+        let counters = Arc::clone(&counters);
+        children.push(thread::spawn(move || {
+            let cell = counters.get_or(|| Cell::new(0));
+            for _ in 0..NITERATIONS {
+                cell.set(cell.get() + 1);
+            }
+        }));
+    }
+    for child in children {
+        let _ = child.join();
+    }
+
+    let mut counters = Arc::try_unwrap(counters).unwrap_or_else(|_| panic!("workers still hold a reference"));
+    counters.iter_mut().map(|cell| cell.get()).sum()
+}
+
+fn bench_counters(c: &mut Criterion) {
+    let mut group = c.benchmark_group("counters");
+
+    group.bench_function("atomic_seqcst", |b| b.iter(|| atomic_counter(Ordering::SeqCst)));
+    group.bench_function("atomic_relaxed", |b| b.iter(|| atomic_counter(Ordering::Relaxed)));
+    group.bench_function("mutex", |b| b.iter(mutex_counter));
+    group.bench_function("thread_local_shard", |b| b.iter(thread_local_counter));
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_counters);
+criterion_main!(benches);
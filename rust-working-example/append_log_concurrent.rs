@@ -0,0 +1,148 @@
+use std::cell::UnsafeCell;
+use std::sync::atomic::{AtomicBool, AtomicPtr, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+
+static NTHREADS: u32 = 10;
+static NITERATIONS: u32 = 1_000_000;
+static NBUCKETS: usize = 32;
+
+struct Slot<T> {
+    value: UnsafeCell<Option<T>>,
+    ready: AtomicBool,
+}
+
+impl<T> Slot<T> {
+    fn new() -> Self {
+        Slot {
+            value: UnsafeCell::new(None),
+            ready: AtomicBool::new(false),
+        }
+    }
+}
+
+unsafe impl<T: Send> Sync for Slot<T> {}
+
+struct Bucket<T>(Vec<Slot<T>>);
+
+/// Lock-free append-only vector in the style of the `boxcar` crate: bucket
+/// `i` holds `2^i` slots and is allocated lazily on the first write into it.
+pub struct AppendLog<T> {
+    buckets: Vec<AtomicPtr<Bucket<T>>>,
+    len: AtomicUsize,
+}
+
+/// Maps a global index to (bucket, bucket length, offset within bucket).
+fn locate(index: usize) -> (usize, usize, usize) {
+    let bucket = usize::BITS as usize - 1 - (index + 1).leading_zeros() as usize;
+    let bucket_len = 1usize << bucket;
+    let offset = index + 1 - bucket_len;
+    (bucket, bucket_len, offset)
+}
+
+impl<T> Default for AppendLog<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> AppendLog<T> {
+    pub fn new() -> Self {
+        AppendLog {
+            buckets: (0..NBUCKETS).map(|_| AtomicPtr::new(std::ptr::null_mut())).collect(),
+            len: AtomicUsize::new(0),
+        }
+    }
+
+    /// Reserves the next index with a single fetch-add, then publishes the
+    /// value with a `Release` store so readers that observe `ready` via
+    /// `Acquire` also see the written value.
+    pub fn push(&self, value: T) -> usize {
+        let index = self.len.fetch_add(1, Ordering::SeqCst);
+        let (bucket, bucket_len, offset) = locate(index);
+
+        let existing = self.buckets[bucket].load(Ordering::Acquire);
+        let bucket_ptr = if existing.is_null() {
+            let new_bucket = Box::into_raw(Box::new(Bucket((0..bucket_len).map(|_| Slot::new()).collect())));
+            match self.buckets[bucket].compare_exchange(
+                std::ptr::null_mut(),
+                new_bucket,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => new_bucket,
+                Err(winner) => {
+                    // Lost the race to allocate this bucket; drop our copy
+                    // and use the one the winning thread installed.
+                    unsafe { drop(Box::from_raw(new_bucket)) };
+                    winner
+                }
+            }
+        } else {
+            existing
+        };
+
+        let slot = &unsafe { &*bucket_ptr }.0[offset];
+        unsafe { *slot.value.get() = Some(value) };
+        slot.ready.store(true, Ordering::Release);
+
+        index
+    }
+
+    /// Iterates entries `0..len`, skipping any slot whose write has been
+    /// reserved but not yet published.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        let len = self.len.load(Ordering::Acquire);
+        (0..len).filter_map(move |index| {
+            let (bucket, _, offset) = locate(index);
+            let bucket_ptr = self.buckets[bucket].load(Ordering::Acquire);
+            if bucket_ptr.is_null() {
+                return None;
+            }
+            let slot = &unsafe { &*bucket_ptr }.0[offset];
+            if slot.ready.load(Ordering::Acquire) {
+                unsafe { &*slot.value.get() }.as_ref()
+            } else {
+                None
+            }
+        })
+    }
+}
+
+impl<T> Drop for AppendLog<T> {
+    fn drop(&mut self) {
+        for bucket in &self.buckets {
+            let ptr = bucket.load(Ordering::Acquire);
+            if !ptr.is_null() {
+                unsafe { drop(Box::from_raw(ptr)) };
+            }
+        }
+    }
+}
+
+unsafe impl<T: Send> Sync for AppendLog<T> {}
+
+fn main() {
+    let log = Arc::new(AppendLog::new());
+
+    let mut children = vec![];
+
+    for _ in 0..NTHREADS {
+        let log = Arc::clone(&log);
+        children.push(thread::spawn(move || {
+            let mut partial: u64 = 0;
+            for _ in 0..NITERATIONS {
+                partial += 1;
+            }
+            log.push(partial);
+        }));
+    }
+
+    for child in children {
+        let _ = child.join();
+    }
+
+    let total: u64 = log.iter().sum();
+
+    println!("{}", total);
+}
@@ -0,0 +1,179 @@
+use std::cell::{Cell, UnsafeCell};
+use std::env;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use thread_local::ThreadLocal;
+
+fn config() -> (u32, u32) {
+    let nthreads = env::var("NTHREADS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(10);
+    let niterations = env::var("NITERATIONS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(1_000_000);
+    (nthreads, niterations)
+}
+
+struct Counter {
+    val: u32,
+}
+
+/// A plain `u32` shared across threads with no synchronization at all —
+/// the baseline `rust-intermediate-attempts/` programs aim for this, but
+/// `concurrent0.rs` doesn't compile and `concurrent2.rs`/`concurrent3.rs`
+/// give each thread its own `move`d copy instead of real shared state. This
+/// wraps the racy increment directly so it can actually be timed here.
+struct Racy(UnsafeCell<u32>);
+
+unsafe impl Sync for Racy {}
+
+fn run_naive(nthreads: u32, niterations: u32) -> (Duration, u32) {
+    let counter = Arc::new(Racy(UnsafeCell::new(0)));
+    let start = Instant::now();
+
+    let mut children = vec![];
+    for _ in 0..nthreads {
+        let counter = Arc::clone(&counter);
+        children.push(thread::spawn(move || {
+            for _ in 0..niterations {
+                unsafe {
+                    *counter.0.get() += 1;
+                }
+            }
+        }));
+    }
+    for child in children {
+        let _ = child.join();
+    }
+
+    let elapsed = start.elapsed();
+    let val = unsafe { *counter.0.get() };
+    (elapsed, val)
+}
+
+fn run_mutex(nthreads: u32, niterations: u32) -> (Duration, u32) {
+    let counter = Arc::new(Mutex::new(Counter { val: 0 }));
+    let start = Instant::now();
+
+    let mut children = vec![];
+    for _ in 0..nthreads {
+        let counter = Arc::clone(&counter);
+        children.push(thread::spawn(move || {
+            for _ in 0..niterations {
+                counter.lock().unwrap().val += 1;
+            }
+        }));
+    }
+    for child in children {
+        let _ = child.join();
+    }
+
+    let elapsed = start.elapsed();
+    let val = counter.lock().unwrap().val;
+    (elapsed, val)
+}
+
+fn run_atomic(nthreads: u32, niterations: u32, ordering: Ordering) -> (Duration, usize) {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let start = Instant::now();
+
+    let mut children = vec![];
+    for _ in 0..nthreads {
+        let counter = Arc::clone(&counter);
+        children.push(thread::spawn(move || {
+            for _ in 0..niterations {
+                counter.fetch_add(1, ordering);
+            }
+        }));
+    }
+    for child in children {
+        let _ = child.join();
+    }
+
+    (start.elapsed(), counter.load(Ordering::SeqCst))
+}
+
+fn run_thread_local(nthreads: u32, niterations: u32) -> (Duration, u64) {
+    let counters: Arc<ThreadLocal<Cell<u64>>> = Arc::new(ThreadLocal::new());
+    let start = Instant::now();
+
+    let mut children = vec![];
+    for _ in 0..nthreads {
+        let counters = Arc::clone(&counters);
+        children.push(thread::spawn(move || {
+            let cell = counters.get_or(|| Cell::new(0));
+            for _ in 0..niterations {
+                cell.set(cell.get() + 1);
+            }
+        }));
+    }
+    for child in children {
+        let _ = child.join();
+    }
+
+    let elapsed = start.elapsed();
+    let mut counters = Arc::try_unwrap(counters).unwrap_or_else(|_| panic!("workers still hold a reference"));
+    let total = counters.iter_mut().map(|cell| cell.get()).sum();
+
+    (elapsed, total)
+}
+
+fn throughput(elapsed: Duration, total: u64) -> f64 {
+    total as f64 / elapsed.as_secs_f64()
+}
+
+fn main() {
+    let (nthreads, niterations) = config();
+    let expected = (nthreads as u64) * (niterations as u64);
+
+    println!("nthreads={} niterations={} expected={}", nthreads, niterations, expected);
+
+    // Unsound: plain reads/writes racing across threads, so the total below
+    // is whatever interleaving happened to occur, not `expected`. Timed but
+    // never asserted, to show what the other strategies are paying to avoid.
+    let (elapsed, total) = run_naive(nthreads, niterations);
+    println!(
+        "naive (racy):       {:>10.3}s  {:>14.0} incr/sec  (got {}, not {})",
+        elapsed.as_secs_f64(),
+        throughput(elapsed, total as u64),
+        total,
+        expected
+    );
+
+    let (elapsed, total) = run_atomic(nthreads, niterations, Ordering::SeqCst);
+    assert_eq!(total as u64, expected);
+    println!(
+        "atomic (SeqCst):    {:>10.3}s  {:>14.0} incr/sec",
+        elapsed.as_secs_f64(),
+        throughput(elapsed, total as u64)
+    );
+
+    let (elapsed, total) = run_atomic(nthreads, niterations, Ordering::Relaxed);
+    assert_eq!(total as u64, expected);
+    println!(
+        "atomic (Relaxed):   {:>10.3}s  {:>14.0} incr/sec",
+        elapsed.as_secs_f64(),
+        throughput(elapsed, total as u64)
+    );
+
+    let (elapsed, total) = run_mutex(nthreads, niterations);
+    assert_eq!(total as u64, expected);
+    println!(
+        "mutex:              {:>10.3}s  {:>14.0} incr/sec",
+        elapsed.as_secs_f64(),
+        throughput(elapsed, total as u64)
+    );
+
+    let (elapsed, total) = run_thread_local(nthreads, niterations);
+    assert_eq!(total, expected);
+    println!(
+        "thread-local shard: {:>10.3}s  {:>14.0} incr/sec",
+        elapsed.as_secs_f64(),
+        throughput(elapsed, total)
+    );
+}
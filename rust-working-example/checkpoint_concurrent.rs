@@ -0,0 +1,49 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Barrier};
+use std::thread;
+
+static NTHREADS: u32 = 10;
+static NITERATIONS: u32 = 1_000_000;
+static NPHASES: u32 = 4;
+
+fn main() {
+    let barrier = Arc::new(Barrier::new(NTHREADS as usize));
+    let counter = Arc::new(AtomicUsize::new(0));
+
+    let mut children = vec![];
+
+    for _ in 0..NTHREADS {
+        let barrier = Arc::clone(&barrier);
+        let counter = Arc::clone(&counter);
+        children.push(thread::spawn(move || {
+            let per_phase = NITERATIONS / NPHASES;
+
+            for phase in 0..NPHASES {
+                for _ in 0..per_phase {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                }
+
+                // No thread reaches this point until every thread has
+                // finished phase `phase`, so the leader's read below always
+                // sees the full phase total.
+                let wait_result = barrier.wait();
+                if wait_result.is_leader() {
+                    let expected = (phase + 1) * per_phase * NTHREADS;
+                    println!("phase {} checkpoint: {}", phase, expected);
+                    assert_eq!(counter.load(Ordering::SeqCst) as u32, expected);
+                }
+
+                // Second rendezvous: hold every thread here until the
+                // leader's validation above has run, so nobody races ahead
+                // into phase N+1 while the checkpoint is still being read.
+                barrier.wait();
+            }
+        }));
+    }
+
+    for child in children {
+        let _ = child.join();
+    }
+
+    println!("{}", counter.load(Ordering::SeqCst));
+}
@@ -0,0 +1,38 @@
+use std::cell::Cell;
+use std::sync::Arc;
+use std::thread;
+
+use thread_local::ThreadLocal;
+
+static NTHREADS: u32 = 10;
+static NITERATIONS: u32 = 1_000_000;
+
+fn main() {
+    let counters: Arc<ThreadLocal<Cell<u64>>> = Arc::new(ThreadLocal::new());
+
+    let mut children = vec![];
+
+    for _ in 0..NTHREADS {
+        let counters = Arc::clone(&counters);
+        children.push(thread::spawn(move || {
+            let cell = counters.get_or(|| Cell::new(0));
+            for _ in 0..NITERATIONS {
+                cell.set(cell.get() + 1);
+            }
+        }));
+    }
+
+    for child in children {
+        let _ = child.join();
+    }
+
+    // Every worker has joined, so this is the only remaining handle to the
+    // store and we can safely take `&mut` to sum the per-thread cells.
+    // Thread ids can be recycled once a thread exits, but no thread touches
+    // the store again after joining, so iterating here never races with a
+    // live writer.
+    let mut counters = Arc::try_unwrap(counters).unwrap_or_else(|_| panic!("workers still hold a reference"));
+    let total: u64 = counters.iter_mut().map(|cell| cell.get()).sum();
+
+    println!("{}", total);
+}
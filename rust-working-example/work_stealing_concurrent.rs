@@ -0,0 +1,84 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+
+use crossbeam_deque::{Steal, Stealer, Worker};
+use rand::Rng;
+
+static NTHREADS: u32 = 10;
+static NITERATIONS: u32 = 1_000_000;
+static CHUNK_SIZE: u32 = 10_000;
+
+fn steal_task(stealers: &[Stealer<u32>], skip: usize) -> Option<u32> {
+    let start = rand::thread_rng().gen_range(0..stealers.len());
+    for offset in 0..stealers.len() {
+        let victim = (start + offset) % stealers.len();
+        if victim == skip {
+            continue;
+        }
+        loop {
+            match stealers[victim].steal() {
+                Steal::Success(chunk) => return Some(chunk),
+                Steal::Retry => continue,
+                Steal::Empty => break,
+            }
+        }
+    }
+    None
+}
+
+fn main() {
+    let total_chunks = (NTHREADS * NITERATIONS / CHUNK_SIZE) as usize;
+
+    let workers: Vec<Worker<u32>> = (0..NTHREADS).map(|_| Worker::new_lifo()).collect();
+    let stealers: Arc<Vec<Stealer<u32>>> = Arc::new(workers.iter().map(Worker::stealer).collect());
+
+    // Seed the deques unevenly on purpose: worker 0 starts with most of the
+    // chunks and the rest get only a trickle, so the only way every thread
+    // finishes around the same time is by stealing from worker 0.
+    let mut pushed = 0;
+    for (i, worker) in workers.iter().enumerate() {
+        let share = if i == 0 {
+            total_chunks - total_chunks / (2 * workers.len())
+        } else {
+            total_chunks / (2 * workers.len() * workers.len())
+        };
+        for _ in 0..share {
+            if pushed >= total_chunks {
+                break;
+            }
+            worker.push(CHUNK_SIZE);
+            pushed += 1;
+        }
+    }
+    while pushed < total_chunks {
+        workers[0].push(CHUNK_SIZE);
+        pushed += 1;
+    }
+
+    let total = Arc::new(AtomicUsize::new(0));
+    let done = Arc::new(AtomicUsize::new(0));
+
+    let mut children = vec![];
+    for (i, worker) in workers.into_iter().enumerate() {
+        let stealers = Arc::clone(&stealers);
+        let total = Arc::clone(&total);
+        let done = Arc::clone(&done);
+        children.push(thread::spawn(move || loop {
+            match worker.pop().or_else(|| steal_task(&stealers, i)) {
+                Some(amount) => {
+                    total.fetch_add(amount as usize, Ordering::SeqCst);
+                    done.fetch_add(1, Ordering::SeqCst);
+                }
+                None if done.load(Ordering::SeqCst) == total_chunks => break,
+                None => continue,
+            }
+        }));
+    }
+
+    for child in children {
+        let _ = child.join();
+    }
+
+    println!("{}", total.load(Ordering::SeqCst));
+}